@@ -0,0 +1,307 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use reqwest::{
+    header::{self, HeaderMap, HeaderValue},
+    Client, ClientBuilder, Method, StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::rate_limit::{RateLimitBuilder, RateLimiter};
+use crate::{
+    finish_response, is_last_page, retry_after, BatchPlayer, BatchPlayers, Error, Platform,
+    PlatformInfo, Player, Playlist, SearchResponse, Season, TierInfo, API_URL, BATCH_CHUNK_SIZE,
+};
+
+/// Builds an `AsyncRlStats`, allowing the per-second/per-hour rate limits
+/// RLS enforces for the given app to be configured up front.
+///
+/// Defaults to conservative limits (6 requests/second, 2000/hour) if
+/// `app_rate_limit` is never called.
+pub struct AsyncRlStatsBuilder<K> {
+    api_key: K,
+    limits: RateLimitBuilder,
+}
+
+impl<K> AsyncRlStatsBuilder<K>
+where
+    K: AsRef<str>,
+{
+    fn new(api_key: K) -> Self {
+        AsyncRlStatsBuilder {
+            api_key,
+            limits: RateLimitBuilder::new(),
+        }
+    }
+
+    /// Registers an additional rate limit window, e.g.
+    /// `app_rate_limit(6, Duration::from_secs(1))` for "6 requests/second".
+    pub fn app_rate_limit(mut self, requests: u32, per: Duration) -> Self {
+        self.limits.push(requests, per);
+        self
+    }
+
+    pub fn build(self) -> Result<AsyncRlStats, Error> {
+        AsyncRlStats::with_rate_limiter(self.api_key, self.limits.build())
+    }
+}
+
+/// An async client for the RocketLeagueStats api.
+///
+/// This mirrors `RlStats`, but is backed by `reqwest::Client` so every
+/// method returns a future instead of blocking the current thread.
+pub struct AsyncRlStats(Client, RateLimiter);
+
+impl AsyncRlStats {
+    pub fn new<K>(api_key: K) -> Result<Self, Error>
+    where
+        K: AsRef<str>,
+    {
+        AsyncRlStats::with_rate_limiter(api_key, RateLimiter::default_limits())
+    }
+
+    /// Starts building an `AsyncRlStats` with custom rate limits.
+    pub fn builder<K>(api_key: K) -> AsyncRlStatsBuilder<K>
+    where
+        K: AsRef<str>,
+    {
+        AsyncRlStatsBuilder::new(api_key)
+    }
+
+    fn with_rate_limiter<K>(api_key: K, rate_limiter: RateLimiter) -> Result<Self, Error>
+    where
+        K: AsRef<str>,
+    {
+        let user_agent = format!(
+            "{} (v {})",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION")
+        );
+
+        let headers = [
+            (header::AUTHORIZATION, api_key.as_ref()),
+            (header::ACCEPT, "application/json"),
+            (header::USER_AGENT, &user_agent),
+        ]
+        .iter()
+        .cloned()
+        .map(|(key, value)| (key, HeaderValue::from_str(value).unwrap()))
+        .collect::<HeaderMap>();
+
+        let client = ClientBuilder::new().default_headers(headers).build()?;
+
+        Ok(AsyncRlStats(client, rate_limiter))
+    }
+
+    pub async fn get_platforms(&self) -> Result<Vec<PlatformInfo>, Error> {
+        self.request("/data/platforms", Method::GET, ()).await
+    }
+
+    pub async fn get_seasons(&self) -> Result<Vec<Season>, Error> {
+        self.request("/data/seasons", Method::GET, ()).await
+    }
+
+    pub async fn get_playlists(&self) -> Result<Vec<Playlist>, Error> {
+        self.request("/data/playlists", Method::GET, ()).await
+    }
+
+    pub async fn get_tiers(&self) -> Result<Vec<TierInfo>, Error> {
+        self.request("/data/tiers", Method::GET, ()).await
+    }
+
+    pub async fn get_player(&self, unique_id: &str, platform: Platform) -> Result<Player, Error> {
+        self.request(
+            format!(
+                "/player?unique_id={}&platform_id={}",
+                unique_id,
+                platform.id()
+            ),
+            Method::GET,
+            (),
+        )
+        .await
+    }
+
+    /// Searches rocketleaguestats' player database, not Rocket League's.
+    pub async fn search_players(
+        &self,
+        display_name: &str,
+        page: u32,
+    ) -> Result<SearchResponse, Error> {
+        self.request(
+            format!(
+                "/search/players?display_name={}&page={}",
+                display_name, page
+            ),
+            Method::GET,
+            (),
+        )
+        .await
+    }
+
+    /// Like `search_players`, but returns a `Stream` over every matching
+    /// player across all pages, fetching lazily as the stream is polled.
+    pub fn search_players_iter<'a>(&'a self, display_name: &'a str) -> SearchPlayersStream<'a> {
+        SearchPlayersStream {
+            client: self,
+            display_name,
+            page: 0,
+            buffer: Vec::new().into_iter(),
+            done: false,
+            in_flight: None,
+        }
+    }
+
+    /// Retrieve more player data faster than you would otherwise be able to.
+    ///
+    /// The max batch size is 10. Players that are not found will simply be
+    /// excluded from the result.
+    pub async fn batch_players(&self, players: Vec<BatchPlayer>) -> Result<Vec<Player>, Error> {
+        self.request("/player/batch", Method::POST, &players).await
+    }
+
+    /// Like `batch_players`, but transparently splits `players` into chunks
+    /// of 10 and concatenates the results, so callers don't have to.
+    pub async fn batch_players_all(&self, players: Vec<BatchPlayer>) -> Result<BatchPlayers, Error> {
+        let mut all_players = Vec::with_capacity(players.len());
+
+        for chunk in players.chunks(BATCH_CHUNK_SIZE) {
+            all_players.extend(self.batch_players(chunk.to_vec()).await?);
+        }
+
+        // Keyed on (id, platform), not just id - the same unique_id can be
+        // batched under two different platforms, and RLS only omits the
+        // pairs it couldn't find, not the bare id.
+        let found = all_players
+            .iter()
+            .map(|p| (p.unique_id.as_str(), p.platform.id))
+            .collect::<HashSet<_>>();
+
+        let missing = players
+            .into_iter()
+            .filter(|p| !found.contains(&(p.id.as_str(), p.platform_id.id())))
+            .map(|p| p.id)
+            .collect();
+
+        Ok(BatchPlayers {
+            players: all_players,
+            missing,
+        })
+    }
+
+    /// Unlike `Platform`/`Tier`, RLS' playlist catalog is dynamic (Psyonix
+    /// adds new playlists over time), so there's no fixed set of variants
+    /// to turn into an enum - instead this takes the `Playlist` you got
+    /// from `get_playlists`, so callers still can't pass a bare, undocumented
+    /// integer.
+    pub async fn get_ranked_leaderboard(&self, playlist: &Playlist) -> Result<Vec<Player>, Error> {
+        self.request(
+            format!("/leaderboard/ranked?playlist_id={}", playlist.id),
+            Method::GET,
+            (),
+        )
+        .await
+    }
+
+    pub async fn get_stat_leaderboard(&self, ty: &str) -> Result<Vec<Player>, Error> {
+        self.request(format!("/leaderboard/stat?type={}", ty), Method::GET, ())
+            .await
+    }
+
+    async fn request<E, T, J>(&self, endpoint: E, method: Method, j: J) -> Result<T, Error>
+    where
+        E: AsRef<str>,
+        T: DeserializeOwned,
+        J: Serialize,
+    {
+        let url = format!("{}{}", API_URL, endpoint.as_ref());
+        let mut retries = 0;
+
+        loop {
+            self.1.acquire_async().await;
+
+            let response = self.0.request(method.clone(), &url).json(&j).send().await?;
+
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                tokio::time::sleep(retry_after(response.headers())).await;
+                retries += 1;
+                continue;
+            }
+
+            let status = response.status();
+            let body = response.text().await?;
+
+            return finish_response(status, body, retries);
+        }
+    }
+}
+
+/// The in-flight `search_players` request a `SearchPlayersStream` is
+/// waiting on, if any.
+type SearchPlayersFuture<'a> = Pin<Box<dyn Future<Output = Result<SearchResponse, Error>> + 'a>>;
+
+/// Stream returned by `AsyncRlStats::search_players_iter`.
+///
+/// Advances through `search_players` pages lazily, only issuing a request
+/// when the current page's players have been exhausted.
+pub struct SearchPlayersStream<'a> {
+    client: &'a AsyncRlStats,
+    display_name: &'a str,
+    page: u32,
+    buffer: std::vec::IntoIter<Player>,
+    done: bool,
+    in_flight: Option<SearchPlayersFuture<'a>>,
+}
+
+impl<'a> Stream for SearchPlayersStream<'a> {
+    type Item = Result<Player, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = Pin::into_inner(self);
+
+        loop {
+            if let Some(player) = this.buffer.next() {
+                return Poll::Ready(Some(Ok(player)));
+            }
+
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            if this.in_flight.is_none() {
+                let client = this.client;
+                let display_name = this.display_name;
+                let page = this.page;
+                this.in_flight = Some(Box::pin(async move {
+                    client.search_players(display_name, page).await
+                }));
+            }
+
+            match this.in_flight.as_mut().unwrap().as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(result) => {
+                    this.in_flight = None;
+
+                    match result {
+                        Ok(resp) => {
+                            this.page += 1;
+                            if is_last_page(this.page, resp.max_results_per_page, resp.total_results)
+                            {
+                                this.done = true;
+                            }
+                            this.buffer = resp.data.into_iter();
+                        }
+                        Err(e) => {
+                            this.done = true;
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,41 +1,177 @@
+use std::collections::HashSet;
+use std::thread;
+use std::time::Duration;
+
 use reqwest::{
     blocking::{Client, ClientBuilder},
-    header::{self, HeaderMap, HeaderValue},
-    Method,
+    header::{self, HeaderMap, HeaderValue, RETRY_AFTER},
+    Method, StatusCode,
 };
 use serde::{de::DeserializeOwned, Serialize};
+use thiserror::Error as ThisError;
 
+mod async_client;
 mod model;
+mod rate_limit;
 
+pub use async_client::AsyncRlStats;
 pub use model::*;
 
+use rate_limit::{RateLimitBuilder, RateLimiter};
+
 const API_URL: &str = "https://api.rocketleaguestats.com/v1";
 
-#[derive(Debug)]
+/// A non-2xx response from the RocketLeagueStats api.
+///
+/// Holds enough of the original HTTP response to let callers inspect what
+/// went wrong, rather than assuming the body is always a `ResponseCode`.
+#[derive(Debug, ThisError)]
+#[error("RLS returned {status} after {retries} retries: {body}")]
+pub struct ResponseError {
+    status: StatusCode,
+    body: String,
+    retries: u32,
+    response_code: Option<ResponseCode>,
+}
+
+impl ResponseError {
+    pub(crate) fn new(status: StatusCode, body: String, retries: u32) -> Self {
+        ResponseError {
+            response_code: serde_json::from_str(&body).ok(),
+            status,
+            body,
+            retries,
+        }
+    }
+
+    /// The HTTP status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The raw, undecoded response body.
+    pub fn response_body(&self) -> &str {
+        &self.body
+    }
+
+    /// How many times the request was transparently retried (due to 429s)
+    /// before this error was produced.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// The decoded `ResponseCode`, if the body happened to be one.
+    pub fn response_code(&self) -> Option<&ResponseCode> {
+        self.response_code.as_ref()
+    }
+}
+
+#[derive(Debug, ThisError)]
 pub enum Error {
+    #[error("invalid request")]
     Invalid,
-    ResponseCode(ResponseCode),
-    ReqwestError(reqwest::Error),
-    JsonError(serde_json::Error),
+    #[error(transparent)]
+    Response(#[from] ResponseError),
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
 }
 
-impl From<reqwest::Error> for Error {
-    fn from(err: reqwest::Error) -> Self {
-        Error::ReqwestError(err)
+impl Error {
+    /// The HTTP status code of the response, if this error came from a
+    /// non-2xx response.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Response(e) => Some(e.status()),
+            _ => None,
+        }
+    }
+
+    /// The raw response body, if this error came from a non-2xx response.
+    pub fn response_body(&self) -> Option<&str> {
+        match self {
+            Error::Response(e) => Some(e.response_body()),
+            _ => None,
+        }
+    }
+
+    /// How many times the request was transparently retried before this
+    /// error was produced. Zero unless this came from a non-2xx response.
+    pub fn retries(&self) -> u32 {
+        match self {
+            Error::Response(e) => e.retries(),
+            _ => 0,
+        }
     }
 }
 
-impl From<serde_json::Error> for Error {
-    fn from(err: serde_json::Error) -> Self {
-        Error::JsonError(err)
+/// The maximum number of players RLS accepts in a single `/player/batch`
+/// request.
+const BATCH_CHUNK_SIZE: usize = 10;
+
+/// The result of `batch_players_all`.
+pub struct BatchPlayers {
+    /// The players RLS found, in no particular order.
+    pub players: Vec<Player>,
+    /// `uniqueId`s from the request that RLS silently omitted from the
+    /// result, i.e. players it could not find.
+    pub missing: Vec<String>,
+}
+
+/// Builds an `RlStats`, allowing the per-second/per-hour rate limits RLS
+/// enforces for the given app to be configured up front.
+///
+/// Defaults to conservative limits (6 requests/second, 2000/hour) if
+/// `app_rate_limit` is never called.
+pub struct RlStatsBuilder<K> {
+    api_key: K,
+    limits: RateLimitBuilder,
+}
+
+impl<K> RlStatsBuilder<K>
+where
+    K: AsRef<str>,
+{
+    fn new(api_key: K) -> Self {
+        RlStatsBuilder {
+            api_key,
+            limits: RateLimitBuilder::new(),
+        }
+    }
+
+    /// Registers an additional rate limit window, e.g.
+    /// `app_rate_limit(6, Duration::from_secs(1))` for "6 requests/second".
+    pub fn app_rate_limit(mut self, requests: u32, per: Duration) -> Self {
+        self.limits.push(requests, per);
+        self
+    }
+
+    pub fn build(self) -> Result<RlStats, Error> {
+        RlStats::with_rate_limiter(self.api_key, self.limits.build())
     }
 }
 
 /// A client for the RocketLeagueStats api.
-pub struct RlStats(Client);
+pub struct RlStats(Client, RateLimiter);
 
 impl RlStats {
     pub fn new<K>(api_key: K) -> Result<Self, Error>
+    where
+        K: AsRef<str>,
+    {
+        RlStats::with_rate_limiter(api_key, RateLimiter::default_limits())
+    }
+
+    /// Starts building an `RlStats` with custom rate limits.
+    pub fn builder<K>(api_key: K) -> RlStatsBuilder<K>
+    where
+        K: AsRef<str>,
+    {
+        RlStatsBuilder::new(api_key)
+    }
+
+    fn with_rate_limiter<K>(api_key: K, rate_limiter: RateLimiter) -> Result<Self, Error>
     where
         K: AsRef<str>,
     {
@@ -57,10 +193,10 @@ impl RlStats {
 
         let client = ClientBuilder::new().default_headers(headers).build()?;
 
-        Ok(RlStats(client))
+        Ok(RlStats(client, rate_limiter))
     }
 
-    pub fn get_platforms(&self) -> Result<Vec<Platform>, Error> {
+    pub fn get_platforms(&self) -> Result<Vec<PlatformInfo>, Error> {
         self.request("/data/platforms", Method::GET, ())
     }
 
@@ -72,15 +208,16 @@ impl RlStats {
         self.request("/data/playlists", Method::GET, ())
     }
 
-    pub fn get_tiers(&self) -> Result<Vec<Tier>, Error> {
+    pub fn get_tiers(&self) -> Result<Vec<TierInfo>, Error> {
         self.request("/data/tiers", Method::GET, ())
     }
 
-    pub fn get_player(&self, unique_id: &str, platform_id: i32) -> Result<Player, Error> {
+    pub fn get_player(&self, unique_id: &str, platform: Platform) -> Result<Player, Error> {
         self.request(
             format!(
                 "/player?unique_id={}&platform_id={}",
-                unique_id, platform_id
+                unique_id,
+                platform.id()
             ),
             Method::GET,
             (),
@@ -99,6 +236,18 @@ impl RlStats {
         )
     }
 
+    /// Like `search_players`, but returns an iterator over every matching
+    /// player across all pages, fetching lazily as the iterator advances.
+    pub fn search_players_iter<'a>(&'a self, display_name: &'a str) -> SearchPlayersIter<'a> {
+        SearchPlayersIter {
+            client: self,
+            display_name,
+            page: 0,
+            buffer: Vec::new().into_iter(),
+            done: false,
+        }
+    }
+
     /// Retrieve more player data faster than you would otherwise be able to.
     ///
     /// The max batch size is 10. Players that are not found will simply be
@@ -107,9 +256,43 @@ impl RlStats {
         self.request("/player/batch", Method::POST, &players)
     }
 
-    pub fn get_ranked_leaderboard(&self, playlist_id: i32) -> Result<Vec<Player>, Error> {
+    /// Like `batch_players`, but transparently splits `players` into chunks
+    /// of 10 and concatenates the results, so callers don't have to.
+    pub fn batch_players_all(&self, players: Vec<BatchPlayer>) -> Result<BatchPlayers, Error> {
+        let mut all_players = Vec::with_capacity(players.len());
+
+        for chunk in players.chunks(BATCH_CHUNK_SIZE) {
+            all_players.extend(self.batch_players(chunk.to_vec())?);
+        }
+
+        // Keyed on (id, platform), not just id - the same unique_id can be
+        // batched under two different platforms, and RLS only omits the
+        // pairs it couldn't find, not the bare id.
+        let found = all_players
+            .iter()
+            .map(|p| (p.unique_id.as_str(), p.platform.id))
+            .collect::<HashSet<_>>();
+
+        let missing = players
+            .into_iter()
+            .filter(|p| !found.contains(&(p.id.as_str(), p.platform_id.id())))
+            .map(|p| p.id)
+            .collect();
+
+        Ok(BatchPlayers {
+            players: all_players,
+            missing,
+        })
+    }
+
+    /// Unlike `Platform`/`Tier`, RLS' playlist catalog is dynamic (Psyonix
+    /// adds new playlists over time), so there's no fixed set of variants
+    /// to turn into an enum - instead this takes the `Playlist` you got
+    /// from `get_playlists`, so callers still can't pass a bare, undocumented
+    /// integer.
+    pub fn get_ranked_leaderboard(&self, playlist: &Playlist) -> Result<Vec<Player>, Error> {
         self.request(
-            format!("/leaderboard/ranked?playlist_id={}", playlist_id),
+            format!("/leaderboard/ranked?playlist_id={}", playlist.id),
             Method::GET,
             (),
         )
@@ -126,11 +309,136 @@ impl RlStats {
         J: Serialize,
     {
         let url = format!("{}{}", API_URL, endpoint.as_ref());
-        let body = self.0.request(method, &url).json(&j).send()?.text()?;
+        let mut retries = 0;
+
+        loop {
+            self.1.acquire();
+
+            let response = self.0.request(method.clone(), &url).json(&j).send()?;
+
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                thread::sleep(retry_after(response.headers()));
+                retries += 1;
+                continue;
+            }
+
+            let status = response.status();
+            let body = response.text()?;
+
+            return finish_response(status, body, retries);
+        }
+    }
+}
+
+/// Iterator returned by `RlStats::search_players_iter`.
+///
+/// Advances through `search_players` pages lazily, only issuing a request
+/// when the current page's players have been exhausted.
+pub struct SearchPlayersIter<'a> {
+    client: &'a RlStats,
+    display_name: &'a str,
+    page: u32,
+    buffer: std::vec::IntoIter<Player>,
+    done: bool,
+}
+
+impl<'a> Iterator for SearchPlayersIter<'a> {
+    type Item = Result<Player, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(player) = self.buffer.next() {
+                return Some(Ok(player));
+            }
+
+            if self.done {
+                return None;
+            }
 
-        match serde_json::from_str::<T>(&body) {
-            Ok(r) => Ok(r),
-            _ => Err(Error::ResponseCode(serde_json::from_str(&body)?)),
+            match self.client.search_players(self.display_name, self.page) {
+                Ok(resp) => {
+                    self.page += 1;
+                    if is_last_page(self.page, resp.max_results_per_page, resp.total_results) {
+                        self.done = true;
+                    }
+                    self.buffer = resp.data.into_iter();
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
         }
     }
 }
+
+/// Whether `page` (the *next* page to fetch, i.e. already incremented past
+/// the page that was just read) is beyond `total_results`, given a
+/// `search_players` page holds at most `max_results_per_page` players.
+///
+/// Shared by `SearchPlayersIter` and `SearchPlayersStream`. Treats a
+/// non-positive `max_results_per_page` as "no more pages" so a malformed
+/// response can't spin the pagination forever.
+pub(crate) fn is_last_page(page: u32, max_results_per_page: i32, total_results: i32) -> bool {
+    max_results_per_page <= 0
+        || i64::from(page) * i64::from(max_results_per_page) >= i64::from(total_results)
+}
+
+/// Turns a completed (non-429) response into a result, shared by the
+/// blocking and async clients' `request` loops.
+fn finish_response<T>(status: StatusCode, body: String, retries: u32) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    if !status.is_success() {
+        return Err(Error::Response(ResponseError::new(status, body, retries)));
+    }
+
+    Ok(serde_json::from_str(&body)?)
+}
+
+/// Reads the `Retry-After` header (in seconds) off a 429 response, falling
+/// back to a second if it is missing or malformed.
+fn retry_after(headers: &HeaderMap) -> Duration {
+    let secs = headers
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(1);
+
+    Duration::from_secs(secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_last_page;
+
+    #[test]
+    fn not_last_page_with_a_remainder_page_left() {
+        // 25 results, 10/page: page 0 consumed, page 1 (10..20) still owed.
+        assert!(!is_last_page(1, 10, 25));
+    }
+
+    #[test]
+    fn last_page_on_an_exact_multiple_total() {
+        // 20 results, 10/page: after consuming pages 0 and 1, nothing left.
+        assert!(is_last_page(2, 10, 20));
+    }
+
+    #[test]
+    fn last_page_with_a_remainder_page_consumed() {
+        // 25 results, 10/page: after consuming pages 0, 1 and 2, done.
+        assert!(is_last_page(3, 10, 25));
+    }
+
+    #[test]
+    fn last_page_when_total_results_is_zero() {
+        assert!(is_last_page(0, 10, 0));
+    }
+
+    #[test]
+    fn last_page_when_max_results_per_page_is_non_positive() {
+        assert!(is_last_page(0, 0, 100));
+        assert!(is_last_page(0, -1, 100));
+    }
+}
@@ -1,7 +1,9 @@
 use std::collections::BTreeMap;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 /// A possible json error.
-/// 
+///
 /// See http://documentation.rocketleaguestats.com/#response-codes
 #[derive(Debug, Deserialize)]
 pub struct ResponseCode {
@@ -9,14 +11,11 @@ pub struct ResponseCode {
     pub message: String,
 }
 
-/// A platform that RocketLeague supports.
+/// A platform that RocketLeague supports, as returned by `get_platforms`.
+///
+/// See the `Platform` enum for a strongly-typed `platform_id`.
 #[derive(Clone, Debug, Deserialize)]
-pub struct Platform {
-    /// Some known IDs:
-    /// 
-    /// * 1 is Steam
-    /// * 2 is PS4
-    /// * 3 is XboxOne
+pub struct PlatformInfo {
     pub id: i32,
     pub name: String,
 }
@@ -31,7 +30,7 @@ pub struct Season {
     #[serde(rename = "startedOn")]
     pub started_on: i64,
     /// This is a unix timestamp.
-    /// 
+    ///
     /// This field will be `None` if the season has not yet ended.
     #[serde(rename = "endedOn")]
     pub ended_on: Option<i64>,
@@ -51,61 +50,20 @@ pub struct Population {
 #[derive(Debug, Deserialize)]
 pub struct Playlist {
     pub id: i32,
-    /// See the `Platform` struct.
+    /// See the `Platform` enum.
     #[serde(rename = "platformId")]
     pub platform_id: i32,
     pub name: String,
     pub population: Population,
 }
 
-/// A RocketLeague ranked tier.
+/// A RocketLeague ranked tier, as returned by `get_tiers`.
+///
+/// See the `Tier` enum for a strongly-typed equivalent.
 #[derive(Debug, Deserialize)]
-pub struct Tier {
-    /// Increments for every tier and sub-tier.
-    /// 
-    /// Example:
-    /// 
-    /// ```no-run
-    /// [
-    ///     Tier {
-    ///         id: 0,
-    ///         name: "Unranked"
-    ///     },
-    ///     Tier {
-    ///         id: 1,
-    ///         name: "Bronze I"
-    ///     },
-    ///     Tier {
-    ///         id: 2,
-    ///         name: "Bronze II"
-    ///     },
-    ///     Tier {
-    ///         id: 3,
-    ///         name: "Bronze III"
-    ///     },
-    ///     Tier {
-    ///         id: 4,
-    ///         name: "Silver I"
-    ///     },
-    ///     Tier {
-    ///         id: 5,
-    ///         name: "Silver II"
-    ///     },
-    ///     Tier {
-    ///         id: 6,
-    ///         name: "Silver III"
-    ///     },
-    ///     Tier {
-    ///         id: 7,
-    ///         name: "Gold I"
-    ///     },
-    ///     Tier {
-    ///         id: 8,
-    ///         name: "Gold II"
-    ///     },
-    ///     ...
-    /// ]
-    /// ```
+pub struct TierInfo {
+    /// Increments for every tier and sub-tier, see `Tier` for the known
+    /// values.
     #[serde(rename = "tierId")]
     pub id: i32,
     #[serde(rename = "tierName")]
@@ -130,12 +88,12 @@ pub struct RankedData {
     pub rank_points: Option<i32>,
     #[serde(rename = "matchesPlayed")]
     pub matches_played: Option<i32>,
-    pub tier: Option<i32>,
+    pub tier: Option<Tier>,
     pub division: Option<i32>,
 }
 
 /// A RocketLeague player.
-/// 
+///
 /// Players will only exist if they have scored at least one goal.
 #[derive(Debug, Deserialize)]
 pub struct Player {
@@ -144,7 +102,7 @@ pub struct Player {
     pub unique_id: String,
     #[serde(rename = "displayName")]
     pub display_name: String,
-    pub platform: Platform,
+    pub platform: PlatformInfo,
     pub avatar: Option<String>,
     #[serde(rename = "profileUrl")]
     pub profile_url: String,
@@ -181,10 +139,215 @@ pub struct SearchResponse {
 }
 
 /// A batch player.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct BatchPlayer {
     #[serde(rename = "uniqueId")]
     pub id: String,
     #[serde(rename = "platformId")]
-    pub platform_id: i32,
-}
\ No newline at end of file
+    pub platform_id: Platform,
+}
+
+/// A platform that RocketLeague supports.
+///
+/// Carries an `Other` variant so that a platform ID RLS hasn't documented
+/// yet still deserializes instead of erroring.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Platform {
+    Steam,
+    Ps4,
+    XboxOne,
+    Other(i32),
+}
+
+impl Platform {
+    /// The integer ID RLS uses for this platform in requests and responses.
+    pub fn id(self) -> i32 {
+        match self {
+            Platform::Steam => 1,
+            Platform::Ps4 => 2,
+            Platform::XboxOne => 3,
+            Platform::Other(id) => id,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Platform::Steam => "Steam",
+            Platform::Ps4 => "PS4",
+            Platform::XboxOne => "XboxOne",
+            Platform::Other(_) => "Other",
+        }
+    }
+}
+
+impl From<i32> for Platform {
+    fn from(id: i32) -> Self {
+        match id {
+            1 => Platform::Steam,
+            2 => Platform::Ps4,
+            3 => Platform::XboxOne,
+            id => Platform::Other(id),
+        }
+    }
+}
+
+impl From<Platform> for i32 {
+    fn from(platform: Platform) -> Self {
+        platform.id()
+    }
+}
+
+impl Serialize for Platform {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i32(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Platform {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Platform::from(i32::deserialize(deserializer)?))
+    }
+}
+
+/// A RocketLeague ranked tier.
+///
+/// Carries an `Other` variant so that a tier ID RLS hasn't documented yet
+/// still deserializes instead of erroring.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Tier {
+    Unranked,
+    BronzeI,
+    BronzeII,
+    BronzeIII,
+    SilverI,
+    SilverII,
+    SilverIII,
+    GoldI,
+    GoldII,
+    GoldIII,
+    PlatinumI,
+    PlatinumII,
+    PlatinumIII,
+    DiamondI,
+    DiamondII,
+    DiamondIII,
+    ChampionI,
+    ChampionII,
+    ChampionIII,
+    GrandChampion,
+    Other(i32),
+}
+
+impl Tier {
+    /// The integer ID RLS uses for this tier, see `TierInfo` for the full
+    /// authoritative list.
+    pub fn id(self) -> i32 {
+        match self {
+            Tier::Unranked => 0,
+            Tier::BronzeI => 1,
+            Tier::BronzeII => 2,
+            Tier::BronzeIII => 3,
+            Tier::SilverI => 4,
+            Tier::SilverII => 5,
+            Tier::SilverIII => 6,
+            Tier::GoldI => 7,
+            Tier::GoldII => 8,
+            Tier::GoldIII => 9,
+            Tier::PlatinumI => 10,
+            Tier::PlatinumII => 11,
+            Tier::PlatinumIII => 12,
+            Tier::DiamondI => 13,
+            Tier::DiamondII => 14,
+            Tier::DiamondIII => 15,
+            Tier::ChampionI => 16,
+            Tier::ChampionII => 17,
+            Tier::ChampionIII => 18,
+            Tier::GrandChampion => 19,
+            Tier::Other(id) => id,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Tier::Unranked => "Unranked",
+            Tier::BronzeI => "Bronze I",
+            Tier::BronzeII => "Bronze II",
+            Tier::BronzeIII => "Bronze III",
+            Tier::SilverI => "Silver I",
+            Tier::SilverII => "Silver II",
+            Tier::SilverIII => "Silver III",
+            Tier::GoldI => "Gold I",
+            Tier::GoldII => "Gold II",
+            Tier::GoldIII => "Gold III",
+            Tier::PlatinumI => "Platinum I",
+            Tier::PlatinumII => "Platinum II",
+            Tier::PlatinumIII => "Platinum III",
+            Tier::DiamondI => "Diamond I",
+            Tier::DiamondII => "Diamond II",
+            Tier::DiamondIII => "Diamond III",
+            Tier::ChampionI => "Champion I",
+            Tier::ChampionII => "Champion II",
+            Tier::ChampionIII => "Champion III",
+            Tier::GrandChampion => "Grand Champion",
+            Tier::Other(_) => "Other",
+        }
+    }
+}
+
+impl From<i32> for Tier {
+    fn from(id: i32) -> Self {
+        match id {
+            0 => Tier::Unranked,
+            1 => Tier::BronzeI,
+            2 => Tier::BronzeII,
+            3 => Tier::BronzeIII,
+            4 => Tier::SilverI,
+            5 => Tier::SilverII,
+            6 => Tier::SilverIII,
+            7 => Tier::GoldI,
+            8 => Tier::GoldII,
+            9 => Tier::GoldIII,
+            10 => Tier::PlatinumI,
+            11 => Tier::PlatinumII,
+            12 => Tier::PlatinumIII,
+            13 => Tier::DiamondI,
+            14 => Tier::DiamondII,
+            15 => Tier::DiamondIII,
+            16 => Tier::ChampionI,
+            17 => Tier::ChampionII,
+            18 => Tier::ChampionIII,
+            19 => Tier::GrandChampion,
+            id => Tier::Other(id),
+        }
+    }
+}
+
+impl From<Tier> for i32 {
+    fn from(tier: Tier) -> Self {
+        tier.id()
+    }
+}
+
+impl Serialize for Tier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i32(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Tier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Tier::from(i32::deserialize(deserializer)?))
+    }
+}
@@ -0,0 +1,186 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A single token bucket for one rate limit window (e.g. "per second" or
+/// "per hour").
+#[derive(Debug)]
+struct Bucket {
+    capacity: u32,
+    remaining: u32,
+    window: Duration,
+    window_start: Instant,
+}
+
+impl Bucket {
+    fn new(capacity: u32, window: Duration) -> Self {
+        Bucket {
+            capacity,
+            remaining: capacity,
+            window,
+            window_start: Instant::now(),
+        }
+    }
+
+    /// Refills the bucket if its window has elapsed. On success a token is
+    /// taken and `Ok(())` is returned; otherwise `Err` carries how much
+    /// longer the caller needs to wait before the window rolls over.
+    fn try_acquire(&mut self) -> Result<(), Duration> {
+        let elapsed = self.window_start.elapsed();
+        if elapsed >= self.window {
+            self.remaining = self.capacity;
+            self.window_start = Instant::now();
+        }
+
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            Ok(())
+        } else {
+            Err(self.window - elapsed)
+        }
+    }
+}
+
+/// Enforces RocketLeagueStats' per-second and per-hour request limits.
+///
+/// Shared behind an `Arc<Mutex<_>>` so every concurrent caller draws from
+/// the same buckets.
+#[derive(Clone, Debug)]
+pub(crate) struct RateLimiter {
+    buckets: Arc<Mutex<Vec<Bucket>>>,
+}
+
+impl RateLimiter {
+    /// RocketLeagueStats' documented default limits: 6 requests/second and
+    /// 2000 requests/hour.
+    pub(crate) fn default_limits() -> Self {
+        RateLimiter::new(vec![
+            (6, Duration::from_secs(1)),
+            (2000, Duration::from_secs(3600)),
+        ])
+    }
+
+    pub(crate) fn new(limits: Vec<(u32, Duration)>) -> Self {
+        let buckets = limits
+            .into_iter()
+            .map(|(capacity, window)| Bucket::new(capacity, window))
+            .collect();
+
+        RateLimiter {
+            buckets: Arc::new(Mutex::new(buckets)),
+        }
+    }
+
+    /// Blocks the current thread until it has taken a token from every
+    /// bucket. Buckets are visited in turn: a token taken from an earlier
+    /// bucket stays taken while a later bucket is waited on.
+    pub(crate) fn acquire(&self) {
+        for i in 0..self.bucket_count() {
+            loop {
+                // Bind the result before matching so the `MutexGuard` the
+                // lock produces is dropped before we sleep on `Err` -
+                // otherwise it stays alive for the whole match and every
+                // other caller blocks on the lock until this sleep ends.
+                let outcome = self.buckets.lock().unwrap()[i].try_acquire();
+                match outcome {
+                    Ok(()) => break,
+                    Err(wait) => thread::sleep(wait),
+                }
+            }
+        }
+    }
+
+    /// Async equivalent of `acquire`, for use from `AsyncRlStats`.
+    pub(crate) async fn acquire_async(&self) {
+        for i in 0..self.bucket_count() {
+            loop {
+                // See the comment in `acquire`: binding first drops the
+                // `MutexGuard` before the `.await`, keeping this future
+                // `Send` and the lock uncontended while we sleep.
+                let outcome = self.buckets.lock().unwrap()[i].try_acquire();
+                match outcome {
+                    Ok(()) => break,
+                    Err(wait) => tokio::time::sleep(wait).await,
+                }
+            }
+        }
+    }
+
+    fn bucket_count(&self) -> usize {
+        self.buckets.lock().unwrap().len()
+    }
+}
+
+/// Accumulates `app_rate_limit` windows for `RlStatsBuilder` and
+/// `AsyncRlStatsBuilder`, resolving to `RateLimiter::default_limits()` if
+/// none were ever added.
+#[derive(Default)]
+pub(crate) struct RateLimitBuilder {
+    limits: Vec<(u32, Duration)>,
+}
+
+impl RateLimitBuilder {
+    pub(crate) fn new() -> Self {
+        RateLimitBuilder::default()
+    }
+
+    pub(crate) fn push(&mut self, requests: u32, per: Duration) {
+        self.limits.push((requests, per));
+    }
+
+    pub(crate) fn build(self) -> RateLimiter {
+        if self.limits.is_empty() {
+            RateLimiter::default_limits()
+        } else {
+            RateLimiter::new(self.limits)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_acquire_exhausts_within_window() {
+        let mut bucket = Bucket::new(2, Duration::from_secs(60));
+        assert!(bucket.try_acquire().is_ok());
+        assert!(bucket.try_acquire().is_ok());
+        assert!(bucket.try_acquire().is_err());
+    }
+
+    #[test]
+    fn try_acquire_does_not_refill_before_window_elapses() {
+        let mut bucket = Bucket::new(1, Duration::from_millis(200));
+        assert!(bucket.try_acquire().is_ok());
+        assert!(bucket.try_acquire().is_err());
+    }
+
+    #[test]
+    fn try_acquire_refills_once_window_elapses() {
+        let mut bucket = Bucket::new(1, Duration::from_millis(20));
+        assert!(bucket.try_acquire().is_ok());
+        assert!(bucket.try_acquire().is_err());
+
+        thread::sleep(Duration::from_millis(30));
+
+        assert!(bucket.try_acquire().is_ok());
+    }
+
+    #[test]
+    fn rate_limiter_acquire_waits_on_the_tightest_bucket() {
+        // A 1-token/20ms bucket alongside a generous one: `acquire` should
+        // block on the tight bucket's refill, not hang or skip it.
+        let limiter = RateLimiter::new(vec![
+            (1, Duration::from_millis(20)),
+            (5, Duration::from_secs(60)),
+        ]);
+
+        limiter.acquire();
+
+        let start = Instant::now();
+        limiter.acquire();
+
+        assert!(start.elapsed() >= Duration::from_millis(15));
+    }
+}